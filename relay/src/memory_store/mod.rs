@@ -12,26 +12,174 @@ use dashmap::DashMap;
 use metrics::increment_counter;
 use serde::{Deserialize, Serialize};
 use serde_json::value::RawValue;
+use std::cmp::Reverse;
 use std::collections::hash_map::Entry::{Occupied, Vacant};
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{BinaryHeap, HashMap, HashSet};
 use std::pin::Pin;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
 use thiserror::Error;
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, Notify};
 use tokio::time::Instant;
 use tokio_stream::{Stream, StreamExt};
 use tracing::{debug, instrument};
 
 type Queues = DashMap<Queue, Mutex<QueueState>, RandomState>;
+type Notifies = DashMap<Queue, Arc<Notify>, RandomState>;
 
 #[derive(Default)]
 struct QueueState {
     jobs: HashMap<JobID, StoredJob, RandomState>,
     queued: Queued,
+
+    /// Jobs that failed and are waiting out their backoff delay before becoming
+    /// eligible again, ordered by when they become due.
+    retrying: BinaryHeap<Reverse<(Instant, JobID)>>,
+
+    /// Jobs enqueued with a future `run_at`, not yet due, ordered by when
+    /// they become due.
+    scheduled: BinaryHeap<Reverse<(Instant, JobID)>>,
+
+    /// Monotonically increasing counter assigned to each Job as it's pushed
+    /// onto the ready queue, used to preserve FIFO order within a priority level.
+    enqueue_seq: u64,
+}
+
+impl QueueState {
+    /// Pushes a Job onto the ready queue with the given priority, preserving
+    /// FIFO order among jobs of equal priority.
+    fn push_ready(&mut self, job_id: JobID, priority: i16) {
+        let seq = self.enqueue_seq;
+        self.enqueue_seq += 1;
+        self.queued.jobs.push(ReadyJob {
+            priority,
+            seq,
+            job_id,
+        });
+    }
+
+    /// Moves any jobs whose backoff delay has elapsed off of `retrying` and onto
+    /// the ready queue, notifying any `next_wait` callers once if at least one
+    /// job was promoted.
+    fn promote_due_retries(&mut self, notify: &Notify) {
+        let now = Instant::now();
+        let mut due = Vec::new();
+        while matches!(self.retrying.peek(), Some(Reverse((retry_at, _))) if *retry_at <= now) {
+            let Reverse((_, job_id)) = self.retrying.pop().unwrap();
+            due.push(job_id);
+        }
+        let promoted = !due.is_empty();
+        for job_id in due {
+            let priority = self
+                .jobs
+                .get_mut(&job_id)
+                .map(|sj| {
+                    sj.retry_at = None;
+                    sj.job.priority
+                })
+                .unwrap_or_default();
+            self.push_ready(job_id, priority);
+        }
+        if promoted {
+            // A batch can promote more than one job; notify_waiters (rather than
+            // notify_one) wakes every parked next_wait caller so the whole batch
+            // gets drained instead of trickling out one job per timeout.
+            notify.notify_waiters();
+        }
+    }
+
+    /// Moves any jobs whose `run_at` has arrived off of `scheduled` and onto
+    /// the ready queue, notifying any `next_wait` callers once if at least one
+    /// job was promoted.
+    fn promote_due_scheduled(&mut self, notify: &Notify) {
+        let now = Instant::now();
+        let mut due = Vec::new();
+        while matches!(self.scheduled.peek(), Some(Reverse((run_at, _))) if *run_at <= now) {
+            let Reverse((_, job_id)) = self.scheduled.pop().unwrap();
+            due.push(job_id);
+        }
+        let promoted = !due.is_empty();
+        for job_id in due {
+            let priority = self.jobs.get(&job_id).map_or(0, |sj| sj.job.priority);
+            self.push_ready(job_id, priority);
+        }
+        if promoted {
+            // See promote_due_retries: wake every parked waiter, not just one,
+            // so a burst of newly-due jobs doesn't drain one at a time.
+            notify.notify_waiters();
+        }
+    }
+}
+
+/// A Job waiting on the ready queue, ordered by `priority` (higher first) and
+/// then by `seq` (lower, i.e. older, first) to keep FIFO order within a
+/// priority level.
+#[derive(Eq, PartialEq)]
+struct ReadyJob {
+    priority: i16,
+    seq: u64,
+    job_id: JobID,
+}
+
+impl Ord for ReadyJob {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.priority
+            .cmp(&other.priority)
+            .then_with(|| other.seq.cmp(&self.seq))
+    }
+}
+
+impl PartialOrd for ReadyJob {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Determines how long a failed Job waits before it's promoted back onto the
+/// ready queue, to avoid hot-looping a retry against a flaky downstream service.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub enum Backoff {
+    /// Retry immediately, as soon as it's reaped.
+    None,
+
+    /// Wait `base * retries` between attempts.
+    Linear(Duration),
+
+    /// Wait `base * factor.pow(retries)` between attempts.
+    Exponential { base: Duration, factor: u32 },
+}
+
+impl Default for Backoff {
+    #[inline]
+    fn default() -> Self {
+        Backoff::None
+    }
+}
+
+impl Backoff {
+    /// Computes the delay to apply for the given retry attempt number.
+    #[inline]
+    #[must_use]
+    pub fn delay(&self, retries: u8) -> Duration {
+        if retries == 0 {
+            // Nothing has failed yet, so there's nothing to back off from -
+            // regardless of variant, e.g. `Exponential`'s `factor.pow(0) == 1`
+            // would otherwise apply `base` as a delay to a job's first attempt.
+            return Duration::ZERO;
+        }
+        match self {
+            Backoff::None => Duration::ZERO,
+            Backoff::Linear(base) => base.saturating_mul(u32::from(retries)),
+            Backoff::Exponential { base, factor } => {
+                base.saturating_mul(factor.saturating_pow(u32::from(retries)))
+            }
+        }
+    }
 }
 
 #[derive(Default)]
 struct Queued {
-    jobs: VecDeque<JobID>,
+    jobs: BinaryHeap<ReadyJob>,
     in_flight: HashSet<JobID, RandomState>,
 }
 
@@ -54,6 +202,57 @@ pub struct StoredJob {
 
     #[serde(skip)]
     heartbeat: Option<Instant>,
+
+    /// When this Job becomes eligible to be promoted back onto the ready queue,
+    /// after a failed attempt. Recomputed from `job.backoff` and `retries` on
+    /// recovery, since it's derived state rather than a source of truth.
+    #[serde(skip)]
+    retry_at: Option<Instant>,
+}
+
+/// A Job that exhausted `max_retries` and was moved aside for operator
+/// inspection and replay instead of being silently dropped.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct DeadLetter {
+    /// The Job as it last existed before being dead-lettered.
+    pub job: Job,
+
+    /// How many attempts had been made by the time it was dead-lettered.
+    pub attempts: u8,
+
+    /// Why the Job was dead-lettered, e.g. the timeout that finally exhausted its retries.
+    pub reason: String,
+}
+
+type DeadLetters = DashMap<Queue, Mutex<Vec<DeadLetter>>, RandomState>;
+
+/// The lifecycle state of a Job as reported by [`Store::info`].
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum JobStatus {
+    /// Waiting to be picked up by a worker, whether on the ready queue, still
+    /// delayed by `run_at`, or waiting out a retry backoff.
+    Queued,
+
+    /// Claimed by a worker via `next`/`next_wait` and awaiting `complete`.
+    InFlight,
+}
+
+/// A point-in-time snapshot of a single Job's status, returned by [`Store::info`]
+/// for dashboards and worker-side progress polling without consuming the Job.
+#[derive(Clone)]
+pub struct JobInfo {
+    /// Whether the Job is queued or claimed by a worker.
+    pub status: JobStatus,
+
+    /// The number of retries already attempted.
+    pub retries: u8,
+
+    /// How long it's been since the last heartbeat, if the Job is in-flight.
+    pub heartbeat: Option<Duration>,
+
+    /// The last persisted state, if any.
+    pub state: Option<Box<RawValue>>,
 }
 
 /// The memory store implementation.
@@ -63,6 +262,18 @@ where
 {
     queues: Queues,
     backing: B,
+
+    /// One `Notify` per queue, signalled whenever a Job becomes ready so that
+    /// `next_wait` callers parked on an empty queue can wake up instead of polling.
+    notifies: Notifies,
+
+    /// Jobs that exhausted their retries, kept per queue for inspection and replay.
+    dead_letters: DeadLetters,
+
+    /// Raw records that couldn't be deserialized or re-enqueued while recovering
+    /// from the backing store, kept around so an operator can inspect and manually fix them
+    /// instead of the whole service failing to start.
+    quarantined: Mutex<Vec<String>>,
 }
 
 impl<B> Store<B>
@@ -71,24 +282,105 @@ where
 {
     /// Creates a new memory store for use.
     ///
+    /// A Job that can't be deserialized, or fails to enqueue, during recovery no longer aborts
+    /// startup: it's logged, counted, and quarantined via [`Store::quarantined`] for an operator
+    /// to inspect later. Dead letters from a prior run are also recovered, so
+    /// [`Store::dead_letters`] reflects the backing store's state across a restart.
+    ///
     /// # Errors
     ///
-    /// Will return `Err` if trying to recover any jobs from the backing store fails.
+    /// This never currently returns `Err`: every recovery failure is quarantined or counted and
+    /// logged instead.
     ///
     #[inline]
     pub async fn new(backing: B) -> Result<Self> {
         let queues = DashMap::default();
+        let notifies = Notifies::default();
+        let dead_letters = DeadLetters::default();
+        let mut quarantined = Vec::new();
 
         // recover any data in persistent store
         {
             let noop = noop::Store::default();
             let mut stream = backing.recover();
             while let Some(result) = stream.next().await {
-                enqueue_in_memory(&noop, &queues, result?).await?;
+                let stored = match result {
+                    Ok(stored) => stored,
+                    Err((raw_bytes, source)) => {
+                        // `source.to_string()` is the deserialize error, not the record
+                        // itself - quarantine the actual bytes the backing store handed
+                        // us so an operator can inspect (or replay) what was really there.
+                        let raw = String::from_utf8_lossy(&raw_bytes).into_owned();
+                        let err = Error::InvalidJob {
+                            raw: raw.clone(),
+                            source,
+                        };
+                        increment_counter!("invalid_job");
+                        tracing::warn!(error = %err, "quarantining invalid job encountered during recovery");
+                        if backing.quarantine(&raw_bytes).await.is_err() {
+                            increment_counter!("errors", "type" => "quarantine_persist");
+                        }
+                        quarantined.push(raw);
+                        continue;
+                    }
+                };
+
+                let raw = serde_json::to_string(&stored).unwrap_or_default();
+                if let Err(source) = enqueue_in_memory(&noop, &queues, &notifies, stored).await {
+                    increment_counter!("invalid_job");
+                    tracing::warn!(job = %raw, error = %source, "quarantining job that failed to enqueue during recovery");
+                    if backing.quarantine(raw.as_bytes()).await.is_err() {
+                        increment_counter!("errors", "type" => "quarantine_persist");
+                    }
+                    quarantined.push(raw);
+                }
             }
         }
 
-        Ok(Self { queues, backing })
+        // Recover dead letters left over from a prior run too, so `dead_letters`
+        // reflects what's actually sitting in the backing store across a
+        // restart rather than reporting empty until something dead-letters again.
+        {
+            let mut stream = backing.recover_dead_letters();
+            while let Some(result) = stream.next().await {
+                match result {
+                    Ok(dead) => {
+                        dead_letters
+                            .entry(dead.job.queue.clone())
+                            .or_default()
+                            .lock()
+                            .await
+                            .push(dead);
+                    }
+                    Err(source) => {
+                        increment_counter!("errors", "type" => "dead_letter_recovery");
+                        tracing::warn!(error = %source, "failed to recover a dead letter from the backing store");
+                    }
+                }
+            }
+        }
+
+        Ok(Self {
+            queues,
+            backing,
+            notifies,
+            dead_letters,
+            quarantined: Mutex::new(quarantined),
+        })
+    }
+
+    /// Lists the raw records that could not be recovered into the Job queues,
+    /// for an operator to inspect and manually fix.
+    #[inline]
+    pub async fn quarantined(&self) -> Vec<String> {
+        self.quarantined.lock().await.clone()
+    }
+
+    /// Returns the `Notify` used to wake `next_wait` callers for `queue`,
+    /// creating it if this is the first time the queue has been seen.
+    #[inline]
+    fn notify_for(&self, queue: &str) -> Arc<Notify> {
+        get_or_insert_notify(&self.notifies, queue)
     }
 
     /// Enqueues the provided Job.
@@ -105,8 +397,9 @@ where
             in_flight: false,
             state: None,
             heartbeat: None,
+            retry_at: None,
         };
-        enqueue_in_memory(&self.backing, &self.queues, stored).await
+        enqueue_in_memory(&self.backing, &self.queues, &self.notifies, stored).await
     }
 
     /// Resets/Extends the timeout timestamp.
@@ -182,6 +475,100 @@ where
         }
     }
 
+    /// Lists the Jobs in `queue` that exhausted their retries and were moved
+    /// aside instead of being dropped.
+    #[inline]
+    pub async fn dead_letters(&self, queue: &str) -> Vec<DeadLetter> {
+        match self.dead_letters.get(queue) {
+            None => Vec::new(),
+            Some(m) => m.lock().await.clone(),
+        }
+    }
+
+    /// Re-enqueues a dead-lettered Job with its retry count reset, removing it
+    /// from the dead-letter list.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the Job is not found in the dead-letter list for `queue`, or there
+    /// was an issue writing it back to the backing store.
+    #[inline]
+    pub async fn requeue_dead(&self, queue: &str, job_id: &str) -> Result<()> {
+        let persist_data = match self.dead_letters.get(queue) {
+            None => None,
+            Some(m) => m
+                .lock()
+                .await
+                .iter()
+                .find(|d| d.job.id == job_id)
+                .map(|d| d.job.persist_data),
+        };
+
+        let Some(persist_data) = persist_data else {
+            return Err(Error::JobNotFound {
+                job_id: job_id.to_owned(),
+                queue: queue.to_owned(),
+            });
+        };
+
+        if persist_data {
+            self.backing.remove_dead_letter(queue, job_id).await?;
+        }
+
+        // Only commit to dropping the dead letter from the in-memory list once
+        // the backing store has actually removed it (or never needed to): if
+        // remove_dead_letter above failed, we returned before this and the dead
+        // letter is still sitting in `dead_letters` to retry later instead of
+        // being lost from both the dead-letter list and the live queue.
+        let dead = match self.dead_letters.get(queue) {
+            None => None,
+            Some(m) => {
+                let mut lock = m.lock().await;
+                lock.iter()
+                    .position(|d| d.job.id == job_id)
+                    .map(|i| lock.remove(i))
+            }
+        };
+
+        match dead {
+            None => Err(Error::JobNotFound {
+                job_id: job_id.to_owned(),
+                queue: queue.to_owned(),
+            }),
+            Some(dead) => self.enqueue(dead.job).await,
+        }
+    }
+
+    /// Looks up a Job's current status without consuming it, for dashboards and
+    /// worker-side progress polling. Backs a `GET /queues/{queue}/jobs/{id}`
+    /// endpoint on the HTTP server.
+    ///
+    /// Returns `Ok(None)` if the queue or the Job within it doesn't exist.
+    ///
+    /// # Errors
+    ///
+    /// This never currently errors; it returns `Result` for symmetry with the rest of `Store`
+    /// and so a future backing-store-backed lookup can surface an `Err` without a breaking change.
+    #[inline]
+    pub async fn info(&self, queue: &str, job_id: &str) -> Result<Option<JobInfo>> {
+        match self.queues.get(queue) {
+            None => Ok(None),
+            Some(m) => {
+                let lock = m.lock().await;
+                Ok(lock.jobs.get(job_id).map(|sj| JobInfo {
+                    status: if lock.queued.in_flight.contains(job_id) {
+                        JobStatus::InFlight
+                    } else {
+                        JobStatus::Queued
+                    },
+                    retries: sj.retries,
+                    heartbeat: sj.heartbeat.map(|h| h.elapsed()),
+                    state: sj.state.clone(),
+                }))
+            }
+        }
+    }
+
     /// Retrieves the nex available Job or None if there are no Job yet available.
     ///
     /// # Errors
@@ -192,8 +579,11 @@ where
         match self.queues.get(queue) {
             None => Ok(None),
             Some(m) => {
+                let notify = self.notify_for(queue);
                 let mut lock = m.lock().await;
-                if let Some(job_id) = lock.queued.jobs.pop_front() {
+                lock.promote_due_retries(&notify);
+                lock.promote_due_scheduled(&notify);
+                if let Some(job_id) = lock.queued.jobs.pop().map(|r| r.job_id) {
                     lock.queued.in_flight.insert(job_id.clone());
 
                     // TODO: in-flight must be persisted here
@@ -227,6 +617,35 @@ where
         }
     }
 
+    /// Retrieves the next available Job, parking the caller until one becomes
+    /// available or `timeout` elapses rather than requiring the caller to poll
+    /// `next` in a loop.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the `Job` is not found.
+    #[inline]
+    pub async fn next_wait(&self, queue: &str, timeout: Duration) -> Result<Option<Job>> {
+        let notify = self.notify_for(queue);
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            if let Some(job) = self.next(queue).await? {
+                return Ok(Some(job));
+            }
+
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Ok(None);
+            }
+
+            tokio::select! {
+                _ = notify.notified() => {}
+                _ = tokio::time::sleep(remaining) => return Ok(None),
+            }
+        }
+    }
+
     /// Checks all Jobs marked as in-flight for timeouts.
     #[inline]
     pub async fn reap_timeouts(&self) -> Pin<Box<dyn Stream<Item = Result<Job>> + Send + '_>> {
@@ -236,9 +655,14 @@ where
         let mut queue_job_ids: HashMap<String, Vec<String>, RandomState> = HashMap::default();
 
         for v in self.queues.iter() {
+            let notify = self.notify_for(v.key());
             let mut lock = v.lock().await;
             let state = &mut *lock;
             let mut stored = Vec::new();
+            let mut ready = Vec::new();
+
+            state.promote_due_retries(&notify);
+            state.promote_due_scheduled(&notify);
 
             state
                 .queued
@@ -259,11 +683,18 @@ where
                                 } else {
                                     j.retries += 1;
                                     j.heartbeat = None;
-                                    state.queued.jobs.push_front(j.job.id.clone());
+                                    let delay = j.job.backoff.delay(j.retries);
+                                    if delay.is_zero() {
+                                        ready.push((j.job.id.clone(), j.job.priority));
+                                    } else {
+                                        let retry_at = Instant::now() + delay;
+                                        j.retry_at = Some(retry_at);
+                                        state.retrying.push(Reverse((retry_at, j.job.id.clone())));
+                                    }
                                     if j.job.persist_data {
                                         stored.push(j.clone());
                                     }
-                                    debug!("retrying job {}, retries {}", &j.job.id, &j.retries);
+                                    debug!("retrying job {}, retries {}, delay {:?}", &j.job.id, &j.retries, delay);
                                     increment_counter!("retries", "queue" => j.job.queue.clone());
                                     false
                                 }
@@ -276,6 +707,14 @@ where
                     }
                 });
 
+            let promoted = !ready.is_empty();
+            for (job_id, priority) in ready {
+                state.push_ready(job_id, priority);
+            }
+            if promoted {
+                notify.notify_waiters();
+            }
+
             for j in stored {
                 if self
                     .backing
@@ -306,7 +745,28 @@ where
                                 continue;
                             }
                             Some(sj) => {
+                                let dead = DeadLetter {
+                                    job: sj.job.clone(),
+                                    // the first attempt plus every retry
+                                    attempts: sj.retries.saturating_add(1),
+                                    reason: format!(
+                                        "exceeded max_retries ({}) after timing out",
+                                        sj.job.max_retries
+                                    ),
+                                };
                                 if sj.job.persist_data {
+                                    // Record the dead letter before removing the live job: if
+                                    // the process dies between the two, a job stuck as "live"
+                                    // forever is merely retried, but one removed without ever
+                                    // landing in the dead-letter table is gone for good.
+                                    self.backing
+                                        .dead_letter(&dead)
+                                        .await
+                                        .map_err(|e| Error::Reaper {
+                                            job_id: sj.job.id.clone(),
+                                            queue: sj.job.queue.clone(),
+                                            message: e.to_string(),
+                                        })?;
                                     self.backing
                                         .remove(sj)
                                         .await
@@ -316,6 +776,12 @@ where
                                             message: e.to_string(),
                                         })?;
                                 }
+                                self.dead_letters
+                                    .entry(queue.clone())
+                                    .or_default()
+                                    .lock()
+                                    .await
+                                    .push(dead);
                                 lock.queued.in_flight.remove(&job_id);
                                 // unwrap is safe because to be here we found it in the jobs HashMap already
                                 yield Ok(lock.jobs.remove(&job_id).unwrap().job);
@@ -330,11 +796,28 @@ where
     }
 }
 
+/// Converts a wall-clock `run_at` into a monotonic `Instant` relative to now,
+/// since the scheduling heaps are ordered by `Instant` but `run_at` has to be
+/// persisted as wall-clock time to survive restarts.
+#[inline]
+fn instant_for(run_at: SystemTime) -> Instant {
+    let delay = run_at
+        .duration_since(SystemTime::now())
+        .unwrap_or(Duration::ZERO);
+    Instant::now() + delay
+}
+
 #[inline]
-async fn enqueue_in_memory<B>(backing: &B, queues: &Queues, stored: StoredJob) -> Result<()>
+async fn enqueue_in_memory<B>(
+    backing: &B,
+    queues: &Queues,
+    notifies: &Notifies,
+    stored: StoredJob,
+) -> Result<()>
 where
     B: Backing,
 {
+    let notify = get_or_insert_notify(notifies, &stored.job.queue);
     match queues.get(&stored.job.queue) {
         None => {
             // not found
@@ -345,41 +828,77 @@ where
             enqueue_in_memory_inner(
                 backing,
                 &mut *queues.get(&stored.job.queue).unwrap().lock().await,
+                &notify,
                 stored,
             )
             .await
         }
-        Some(m) => enqueue_in_memory_inner(backing, &mut *m.lock().await, stored).await,
+        Some(m) => enqueue_in_memory_inner(backing, &mut *m.lock().await, &notify, stored).await,
+    }
+}
+
+/// Returns the `Notify` for `queue`, creating and inserting one if this is the
+/// first time it's been seen.
+#[inline]
+fn get_or_insert_notify(notifies: &Notifies, queue: &str) -> Arc<Notify> {
+    if let Entry::Vacant(v) = notifies.entry(queue.to_owned()) {
+        v.insert(Arc::new(Notify::new()));
     }
+    notifies.get(queue).unwrap().clone()
 }
 
 #[inline]
-#[instrument(level = "debug", skip(backing, queue_state, stored), fields(job_id=%stored.job.id))]
+#[instrument(level = "debug", skip(backing, queue_state, notify, stored), fields(job_id=%stored.job.id))]
 async fn enqueue_in_memory_inner<B>(
     backing: &B,
     queue_state: &mut QueueState,
-    stored: StoredJob,
+    notify: &Notify,
+    mut stored: StoredJob,
 ) -> Result<()>
 where
     B: Backing,
 {
-    if let Vacant(v) = queue_state.jobs.entry(stored.job.id.clone()) {
-        debug!("enqueueing job");
-        if stored.job.persist_data {
-            backing.push(&stored).await?;
-        }
-        queue_state.queued.jobs.push_back(stored.job.id.clone());
-        if stored.in_flight {
-            queue_state.queued.in_flight.insert(stored.job.id.clone());
-        }
-        v.insert(stored);
-        Ok(())
-    } else {
-        Err(Error::JobExists {
+    if queue_state.jobs.contains_key(&stored.job.id) {
+        return Err(Error::JobExists {
             job_id: stored.job.id,
             queue: stored.job.queue,
-        })
+        });
     }
+
+    debug!("enqueueing job");
+    if stored.job.persist_data {
+        backing.push(&stored).await?;
+    }
+
+    let job_id = stored.job.id.clone();
+    let priority = stored.job.priority;
+
+    if stored.in_flight {
+        queue_state.queued.in_flight.insert(job_id.clone());
+        queue_state.push_ready(job_id, priority);
+    } else if let Some(run_at) = stored.job.run_at.filter(|&at| at > SystemTime::now()) {
+        // Not yet due - hold it out of the ready queue until `run_at` arrives.
+        queue_state
+            .scheduled
+            .push(Reverse((instant_for(run_at), job_id)));
+    } else {
+        // A fresh enqueue always has retries == 0 and so is immediately ready;
+        // a recovered Job that already had retries recorded re-applies the full
+        // backoff delay from the moment of recovery, since the last-failure
+        // time isn't itself persisted (only the retry count is).
+        let delay = stored.job.backoff.delay(stored.retries);
+        if delay.is_zero() {
+            queue_state.push_ready(job_id, priority);
+            notify.notify_one();
+        } else {
+            let retry_at = Instant::now() + delay;
+            stored.retry_at = Some(retry_at);
+            queue_state.retrying.push(Reverse((retry_at, job_id)));
+        }
+    }
+
+    queue_state.jobs.insert(stored.job.id.clone(), stored);
+    Ok(())
 }
 
 /// Memory store Result type.
@@ -407,6 +926,15 @@ pub enum Error {
         queue: String,
         message: String,
     },
+
+    /// indicates a record in the backing store could not be deserialized into a Job. The raw
+    /// record is quarantined rather than aborting recovery of the rest of the store.
+    #[error("invalid job encountered during recovery: {source}")]
+    InvalidJob {
+        raw: String,
+        #[source]
+        source: BackingError,
+    },
 }
 
 impl Error {
@@ -418,6 +946,7 @@ impl Error {
             | Error::JobNotFound { queue, .. }
             | Error::Reaper { queue, .. } => queue.clone(),
             Error::Backing(e) => e.queue(),
+            Error::InvalidJob { .. } => String::new(),
         }
     }
 
@@ -429,6 +958,69 @@ impl Error {
             Error::JobNotFound { .. } => "job_not_found".to_string(),
             Error::Backing(e) => e.error_type(),
             Error::Reaper { .. } => "reaper".to_string(),
+            Error::InvalidJob { .. } => "invalid_job".to_string(),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_delay_is_zero_on_first_attempt() {
+        let backoffs = [
+            Backoff::None,
+            Backoff::Linear(Duration::from_secs(1)),
+            Backoff::Exponential {
+                base: Duration::from_secs(1),
+                factor: 2,
+            },
+        ];
+        for backoff in backoffs {
+            assert_eq!(backoff.delay(0), Duration::ZERO);
+        }
+    }
+
+    #[test]
+    fn backoff_delay_linear() {
+        let backoff = Backoff::Linear(Duration::from_secs(2));
+        assert_eq!(backoff.delay(3), Duration::from_secs(6));
+    }
+
+    #[test]
+    fn backoff_delay_exponential() {
+        let backoff = Backoff::Exponential {
+            base: Duration::from_secs(1),
+            factor: 2,
+        };
+        assert_eq!(backoff.delay(1), Duration::from_secs(2));
+        assert_eq!(backoff.delay(3), Duration::from_secs(8));
+    }
+
+    #[test]
+    fn ready_job_orders_by_priority_then_fifo() {
+        let mut heap = BinaryHeap::new();
+        heap.push(ReadyJob {
+            priority: 0,
+            seq: 0,
+            job_id: "low-priority-first".to_string(),
+        });
+        heap.push(ReadyJob {
+            priority: 10,
+            seq: 2,
+            job_id: "high-priority-second".to_string(),
+        });
+        heap.push(ReadyJob {
+            priority: 10,
+            seq: 1,
+            job_id: "high-priority-first".to_string(),
+        });
+
+        // Higher priority comes off first; ties within a priority break by
+        // the lower (i.e. older) seq, preserving FIFO order.
+        assert_eq!(heap.pop().unwrap().job_id, "high-priority-first");
+        assert_eq!(heap.pop().unwrap().job_id, "high-priority-second");
+        assert_eq!(heap.pop().unwrap().job_id, "low-priority-first");
+    }
+}